@@ -1,13 +1,66 @@
 use std::{
+    error::Error,
+    fmt,
     sync::{mpsc, Arc, Mutex},
     thread::{self, JoinHandle},
 };
 
-type Job = Box<dyn FnOnce() + Send + 'static>;
+mod request;
+mod response;
+mod router;
+
+pub use request::{HttpMethod, Request};
+pub use response::Response;
+pub use router::Router;
+
+pub type Job = Box<dyn FnOnce() + Send + 'static>;
+
+/// An error returned by [`ThreadPool::new`].
+#[derive(Debug)]
+pub enum PoolCreationError {
+    /// The pool was asked to create zero threads.
+    ZeroSize,
+}
+
+impl fmt::Display for PoolCreationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PoolCreationError::ZeroSize => write!(f, "thread pool size must be greater than zero"),
+        }
+    }
+}
+
+impl Error for PoolCreationError {}
+
+/// An error returned by [`ThreadPool::execute`] when the job could not be
+/// sent to a worker because the job channel is closed. The pool keeps its
+/// own handle to the receiving end, so this does not happen while the pool
+/// is alive.
+///
+/// The rejected job is returned in the `job` field so the caller can
+/// retry it or drop it.
+pub struct ExecuteError {
+    pub job: Job,
+}
+
+impl fmt::Debug for ExecuteError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ExecuteError").finish_non_exhaustive()
+    }
+}
+
+impl fmt::Display for ExecuteError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "failed to send job to thread pool; the job channel is closed")
+    }
+}
+
+impl Error for ExecuteError {}
 
 pub struct ThreadPool {
-    workers: Vec<Worker>,
-    sender: mpsc::Sender<Job>,
+    workers: Mutex<Vec<Worker>>,
+    sender: Option<mpsc::Sender<Job>>,
+    receiver: Arc<Mutex<mpsc::Receiver<Job>>>,
 }
 
 impl ThreadPool {
@@ -15,12 +68,11 @@ impl ThreadPool {
     ///
     /// The size is the number of threads in the pool.
     ///
-    /// # Panics
-    ///
-    /// The `new` function will panic if the size is zero.
-    pub fn new(size: usize) -> ThreadPool {
-        // Make sure `size` is valid.
-        assert!(size > 0);
+    /// Returns `Err(PoolCreationError::ZeroSize)` if `size` is zero.
+    pub fn new(size: usize) -> Result<ThreadPool, PoolCreationError> {
+        if size == 0 {
+            return Err(PoolCreationError::ZeroSize);
+        }
 
         // Create our sender and receiver
         let (sender, receiver) = mpsc::channel();
@@ -36,36 +88,118 @@ impl ThreadPool {
             workers.push(Worker::new(id, Arc::clone(&receiver)));
         }
 
-        ThreadPool {
-            workers,
-            sender,
-        }
+        Ok(ThreadPool {
+            workers: Mutex::new(workers),
+            sender: Some(sender),
+            receiver,
+        })
     }
 
-    pub fn execute<F>(&self, f: F)
+    /// Send `f` to a worker to run.
+    ///
+    /// This does not check for dead workers; call [`ThreadPool::maintain`]
+    /// periodically (e.g. once per accepted connection) so a crashed
+    /// handler doesn't permanently shrink the pool.
+    pub fn execute<F>(&self, f: F) -> Result<(), ExecuteError>
     where
         F: FnOnce() + Send + 'static,
     {
-        // Send our job to a Worker.
-        let job = Box::new(f);
-        self.sender.send(job).unwrap();
+        let job: Job = Box::new(f);
+        self.sender
+            .as_ref()
+            .unwrap()
+            .send(job)
+            .map_err(|mpsc::SendError(job)| ExecuteError { job })
+    }
+
+    /// The number of workers currently running (as opposed to ones that
+    /// panicked or otherwise exited and haven't been replaced yet).
+    pub fn active_workers(&self) -> usize {
+        self.workers
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|worker| {
+                worker
+                    .thread
+                    .as_ref()
+                    .is_some_and(|thread| !thread.is_finished())
+            })
+            .count()
+    }
+
+    /// Replace any worker whose thread has finished (e.g. because its last
+    /// job poisoned the thread) with a fresh one sharing the same id and
+    /// receiver, so the pool keeps its full thread count.
+    ///
+    /// This does a full scan of the pool, so it's kept off the `execute`
+    /// hot path; callers should invoke it periodically instead.
+    pub fn maintain(&self) {
+        let mut workers = self.workers.lock().unwrap();
+
+        for worker in workers.iter_mut() {
+            let is_dead = worker
+                .thread
+                .as_ref()
+                .is_some_and(|thread| thread.is_finished());
+
+            if is_dead {
+                println!("Worker {} died; restarting.", worker.id);
+                *worker = Worker::new(worker.id, Arc::clone(&self.receiver));
+            }
+        }
+    }
+}
+
+impl Drop for ThreadPool {
+    fn drop(&mut self) {
+        // Dropping the sender closes the channel, so each Worker's
+        // `recv()` will return an `Err` and the loop will break.
+        drop(self.sender.take());
+
+        for worker in self.workers.get_mut().unwrap() {
+            println!("Shutting down worker {}", worker.id);
+
+            if let Some(thread) = worker.thread.take() {
+                // A job may have panicked and taken the thread down with it;
+                // that's not a shutdown failure, so don't propagate it.
+                if thread.join().is_err() {
+                    println!("Worker {} had already panicked.", worker.id);
+                }
+            }
+        }
     }
 }
 
 struct Worker {
     id: usize,
-    thread: JoinHandle<()>,
+    thread: Option<JoinHandle<()>>,
 }
 
 impl Worker {
     /// Create a new Worker with the given id.
     pub fn new(id: usize, receiver: Arc<Mutex<mpsc::Receiver<Job>>>) -> Worker {
         let thread = thread::spawn(move || loop {
-            let job = receiver.lock().unwrap().recv().unwrap();
-            println!("Worker {id} got a job; executing.");
-            job();
+            let message = receiver.lock().unwrap().recv();
+
+            match message {
+                Ok(job) => {
+                    println!("Worker {id} got a job; executing.");
+                    // Deliberately don't catch a panicking job here: letting
+                    // the thread actually die is what lets
+                    // `ThreadPool::maintain` detect and replace it.
+                    job();
+                }
+                Err(_) => {
+                    println!("Worker {id} disconnected; shutting down.");
+                    break;
+                }
+            }
         });
 
-        Worker { id, thread }
+        Worker {
+            id,
+            thread: Some(thread),
+        }
     }
 }
\ No newline at end of file
@@ -1,12 +1,13 @@
 use std::{
     fs,
-    io::{prelude::*, BufReader},
+    io::BufReader,
     net::{TcpListener, TcpStream},
+    sync::Arc,
     thread,
     time::Duration,
 };
 
-use hello::ThreadPool;
+use hello::{HttpMethod, Request, Response, Router, ThreadPool};
 
 fn main() {
     let port = 7878u16;
@@ -14,47 +15,71 @@ fn main() {
     let listener = TcpListener::bind(listen_address).unwrap();
     println!("Listening on port {}", port);
 
-    let pool = ThreadPool::new(4);
+    let router = Arc::new(build_router());
+    let pool = ThreadPool::new(4).unwrap();
     for stream in listener.incoming() {
         let stream = stream.unwrap();
+        let router = Arc::clone(&router);
 
-        pool.execute(|| {
-            handle_connection(stream);
+        pool.maintain();
+
+        let result = pool.execute(move || {
+            handle_connection(stream, &router);
         });
+
+        if let Err(err) = result {
+            println!("Failed to dispatch request: {err}");
+        }
     }
 }
 
-fn handle_connection(mut stream: TcpStream) {
-    let buf_reader = BufReader::new(&mut stream);
+fn build_router() -> Router {
+    let mut router = Router::new();
 
-    // A line could be an error if it contains invalid
-    // UTF-8, or if there's a problem reading from the
-    // underlying stream.  We ignore these errors here.
-    let http_request: Vec<_> = buf_reader
-        .lines()
-        .map(|result| result.unwrap())
-        .take_while(|line| !line.is_empty()) // Blank line is end of headers.
-        .collect();
+    router.route(HttpMethod::Get, "/", |_req| {
+        send_file_response(200, "OK", "hello.html")
+    });
 
-    let request_line = &http_request[0];
+    router.route(HttpMethod::Get, "/sleep", |_req| {
+        thread::sleep(Duration::from_secs(5));
+        send_file_response(200, "OK", "hello.html")
+    });
 
-    println!("Incoming request for {}", request_line);
+    router.route(HttpMethod::Get, "/static/*", |req| {
+        let filename = req.path.trim_start_matches("/static/");
+        send_file_response(200, "OK", filename)
+    });
 
-    match &request_line[..] {
-        "GET / HTTP/1.1" => send_response(stream, 200, "OK", "hello.html"),
-        "GET /sleep HTTP/1.1" => {
-            thread::sleep(Duration::from_secs(5));
-            send_response(stream, 200, "OK", "hello.html");
-        }
-        _ => send_response(stream, 404, "NOT FOUND", "404.html"),
-    }
+    router
 }
 
-fn send_response(mut stream: TcpStream, code: u16, reason: &str, filename: &str) {
-    let contents = fs::read_to_string(filename).unwrap();
-    let length = contents.len();
-    let response =
-        format!("HTTP/1.1 {code} {reason}\r\nContent-Length: {length}\r\n\r\n{contents}");
+fn handle_connection(mut stream: TcpStream, router: &Router) {
+    let mut buf_reader = BufReader::new(&mut stream);
+    let req = match Request::parse(&mut buf_reader) {
+        Ok(req) => req,
+        Err(_) => {
+            let response = Response::new(400)
+                .reason("BAD REQUEST")
+                .body(b"Bad Request".to_vec());
+            let _ = response.write_to(&mut stream);
+            return;
+        }
+    };
+
+    println!("Incoming request for {:?} {}", req.method, req.path);
 
-    stream.write_all(response.as_bytes()).unwrap();
+    let response = router.handle(&req);
+    response.write_to(&mut stream).unwrap();
+}
+
+fn send_file_response(code: u16, reason: &str, filename: &str) -> Response {
+    match fs::read(filename) {
+        Ok(contents) => Response::new(code)
+            .reason(reason)
+            .header("Content-Type", "text/html")
+            .body(contents),
+        Err(_) => Response::new(404)
+            .reason("NOT FOUND")
+            .body(fs::read("404.html").unwrap_or_default()),
+    }
 }
@@ -0,0 +1,130 @@
+use std::{
+    collections::HashMap,
+    io::{self, BufRead, BufReader, Read},
+    net::TcpStream,
+};
+
+/// The HTTP method used in a request line, e.g. `GET /path HTTP/1.1`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HttpMethod {
+    Get,
+    Post,
+    Put,
+    Delete,
+    Head,
+    Options,
+    Patch,
+    Connect,
+    Trace,
+}
+
+impl HttpMethod {
+    fn parse(method: &str) -> io::Result<HttpMethod> {
+        match method {
+            "GET" => Ok(HttpMethod::Get),
+            "POST" => Ok(HttpMethod::Post),
+            "PUT" => Ok(HttpMethod::Put),
+            "DELETE" => Ok(HttpMethod::Delete),
+            "HEAD" => Ok(HttpMethod::Head),
+            "OPTIONS" => Ok(HttpMethod::Options),
+            "PATCH" => Ok(HttpMethod::Patch),
+            "CONNECT" => Ok(HttpMethod::Connect),
+            "TRACE" => Ok(HttpMethod::Trace),
+            _ => Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("unsupported HTTP method: {method}"),
+            )),
+        }
+    }
+}
+
+/// A parsed HTTP request.
+#[derive(Debug)]
+pub struct Request {
+    pub method: HttpMethod,
+    pub path: String,
+    pub query: HashMap<String, String>,
+    pub headers: HashMap<String, String>,
+    pub cookies: HashMap<String, String>,
+    pub body: Option<Vec<u8>>,
+}
+
+impl Request {
+    /// Read and parse a single HTTP request from `reader`.
+    pub fn parse(reader: &mut BufReader<&mut TcpStream>) -> io::Result<Request> {
+        let mut request_line = String::new();
+        reader.read_line(&mut request_line)?;
+        let mut parts = request_line.split_whitespace();
+
+        let method = parts
+            .next()
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "missing HTTP method"))?;
+        let method = HttpMethod::parse(method)?;
+
+        let target = parts
+            .next()
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "missing request target"))?;
+        let (path, query) = Self::parse_target(target);
+
+        let mut headers = HashMap::new();
+        loop {
+            let mut line = String::new();
+            reader.read_line(&mut line)?;
+            let line = line.trim_end_matches("\r\n").trim_end_matches('\n');
+            if line.is_empty() {
+                break;
+            }
+
+            if let Some((name, value)) = line.split_once(':') {
+                headers.insert(name.trim().to_lowercase(), value.trim().to_string());
+            }
+        }
+
+        let cookies = headers
+            .get("cookie")
+            .map(|cookie_header| Self::parse_cookies(cookie_header))
+            .unwrap_or_default();
+
+        let body = match headers.get("content-length").and_then(|v| v.parse::<usize>().ok()) {
+            Some(0) | None => None,
+            Some(length) => {
+                let mut buf = vec![0u8; length];
+                reader.read_exact(&mut buf)?;
+                Some(buf)
+            }
+        };
+
+        Ok(Request {
+            method,
+            path,
+            query,
+            headers,
+            cookies,
+            body,
+        })
+    }
+
+    fn parse_target(target: &str) -> (String, HashMap<String, String>) {
+        match target.split_once('?') {
+            Some((path, query_string)) => (path.to_string(), Self::parse_query(query_string)),
+            None => (target.to_string(), HashMap::new()),
+        }
+    }
+
+    fn parse_query(query_string: &str) -> HashMap<String, String> {
+        query_string
+            .split('&')
+            .filter(|pair| !pair.is_empty())
+            .filter_map(|pair| pair.split_once('='))
+            .map(|(key, value)| (key.to_string(), value.to_string()))
+            .collect()
+    }
+
+    fn parse_cookies(cookie_header: &str) -> HashMap<String, String> {
+        cookie_header
+            .split(';')
+            .filter_map(|pair| pair.trim().split_once('='))
+            .map(|(key, value)| (key.to_string(), value.to_string()))
+            .collect()
+    }
+}
@@ -0,0 +1,100 @@
+use std::{
+    collections::HashMap,
+    io::{self, Write},
+    net::TcpStream,
+};
+
+/// An HTTP response, built up fluently and then written to a stream.
+pub struct Response {
+    status_code: u16,
+    reason: String,
+    headers: HashMap<String, String>,
+    cookies: Vec<String>,
+    body: Option<Vec<u8>>,
+}
+
+impl Response {
+    /// Start building a response with the given status code.
+    ///
+    /// The reason phrase defaults to the standard one for well-known codes,
+    /// falling back to `"Unknown"` for anything else.
+    pub fn new(status_code: u16) -> Response {
+        Response {
+            status_code,
+            reason: default_reason(status_code).to_string(),
+            headers: HashMap::new(),
+            cookies: Vec::new(),
+            body: None,
+        }
+    }
+
+    /// Override the default reason phrase for the status code.
+    pub fn reason(mut self, reason: &str) -> Response {
+        self.reason = reason.to_string();
+        self
+    }
+
+    /// Add a response header.
+    pub fn header(mut self, name: &str, value: &str) -> Response {
+        self.headers.insert(name.to_string(), value.to_string());
+        self
+    }
+
+    /// Add a `Set-Cookie` header with the given raw cookie string
+    /// (e.g. `"session=abc; HttpOnly"`).
+    pub fn cookie(mut self, cookie: &str) -> Response {
+        self.cookies.push(cookie.to_string());
+        self
+    }
+
+    /// Set the response body.
+    pub fn body(mut self, body: Vec<u8>) -> Response {
+        self.body = Some(body);
+        self
+    }
+
+    /// Serialize the response and write it to `stream`.
+    pub fn write_to(self, stream: &mut TcpStream) -> io::Result<()> {
+        let body = self.body.unwrap_or_default();
+
+        let mut response = format!("HTTP/1.1 {} {}\r\n", self.status_code, self.reason);
+        response.push_str(&format!("Content-Length: {}\r\n", body.len()));
+
+        for (name, value) in &self.headers {
+            // `Content-Length` is always derived from the body above; skip
+            // any caller-supplied value so it isn't written twice.
+            if name.eq_ignore_ascii_case("content-length") {
+                continue;
+            }
+            response.push_str(&format!("{name}: {value}\r\n"));
+        }
+
+        for cookie in &self.cookies {
+            response.push_str(&format!("Set-Cookie: {cookie}\r\n"));
+        }
+
+        response.push_str("\r\n");
+
+        stream.write_all(response.as_bytes())?;
+        stream.write_all(&body)?;
+        Ok(())
+    }
+}
+
+fn default_reason(status_code: u16) -> &'static str {
+    match status_code {
+        200 => "OK",
+        201 => "Created",
+        204 => "No Content",
+        301 => "Moved Permanently",
+        302 => "Found",
+        304 => "Not Modified",
+        400 => "Bad Request",
+        401 => "Unauthorized",
+        403 => "Forbidden",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        500 => "Internal Server Error",
+        _ => "Unknown",
+    }
+}
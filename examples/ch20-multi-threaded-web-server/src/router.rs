@@ -0,0 +1,60 @@
+use std::collections::HashMap;
+
+use crate::{HttpMethod, Request, Response};
+
+type Handler = Box<dyn Fn(&Request) -> Response + Send + Sync>;
+
+/// Dispatches requests to handlers registered by method and path.
+///
+/// Paths ending in `/*` match any trailing segment, so a single handler
+/// can be registered to serve a whole directory (e.g. `"/static/*"`).
+#[derive(Default)]
+pub struct Router {
+    routes: HashMap<(HttpMethod, String), Handler>,
+}
+
+impl Router {
+    /// Create an empty router.
+    pub fn new() -> Router {
+        Router {
+            routes: HashMap::new(),
+        }
+    }
+
+    /// Register a handler for `method` and `path`.
+    ///
+    /// `path` may end in `/*` to match any path with that prefix.
+    pub fn route<F>(&mut self, method: HttpMethod, path: &str, handler: F)
+    where
+        F: Fn(&Request) -> Response + Send + Sync + 'static,
+    {
+        self.routes
+            .insert((method, path.to_string()), Box::new(handler));
+    }
+
+    /// Find the handler registered for `req` and run it, falling back to a
+    /// default 404 response if nothing matches.
+    pub fn handle(&self, req: &Request) -> Response {
+        match self.find(req.method, &req.path) {
+            Some(handler) => handler(req),
+            None => Response::new(404).body(b"Not Found".to_vec()),
+        }
+    }
+
+    fn find(&self, method: HttpMethod, path: &str) -> Option<&Handler> {
+        if let Some(handler) = self.routes.get(&(method, path.to_string())) {
+            return Some(handler);
+        }
+
+        self.routes
+            .iter()
+            .filter(|((route_method, route_path), _)| {
+                *route_method == method && route_path.ends_with("/*")
+            })
+            .find(|((_, route_path), _)| {
+                let prefix = &route_path[..route_path.len() - 1];
+                path.starts_with(prefix)
+            })
+            .map(|(_, handler)| handler)
+    }
+}
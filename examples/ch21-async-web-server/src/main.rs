@@ -1,10 +1,64 @@
-use std::{error::Error, time::Duration};
+use std::{collections::HashMap, error::Error, future::Future, pin::Pin, sync::Arc, time::Duration};
 use tokio::{
     fs,
     io::{AsyncBufReadExt, AsyncWriteExt, BufReader},
     net::{TcpListener, TcpStream},
 };
 
+type HandlerFuture = Pin<Box<dyn Future<Output = ()> + Send>>;
+type Handler = Box<dyn Fn(TcpStream) -> HandlerFuture + Send + Sync>;
+
+/// An async counterpart to the sync server's `Router`: dispatches by method
+/// and path, with trailing `/*` wildcard support. Handlers own the stream
+/// and are responsible for writing their own response to it.
+#[derive(Default)]
+struct Router {
+    routes: HashMap<(String, String), Handler>,
+}
+
+impl Router {
+    fn new() -> Router {
+        Router {
+            routes: HashMap::new(),
+        }
+    }
+
+    fn route<F, Fut>(&mut self, method: &str, path: &str, handler: F)
+    where
+        F: Fn(TcpStream) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        self.routes.insert(
+            (method.to_string(), path.to_string()),
+            Box::new(move |stream| Box::pin(handler(stream)) as HandlerFuture),
+        );
+    }
+
+    async fn handle(&self, method: &str, path: &str, stream: TcpStream) {
+        match self.find(method, path) {
+            Some(handler) => handler(stream).await,
+            None => send_response(stream, 404, "NOT FOUND", "404.html").await,
+        }
+    }
+
+    fn find(&self, method: &str, path: &str) -> Option<&Handler> {
+        if let Some(handler) = self.routes.get(&(method.to_string(), path.to_string())) {
+            return Some(handler);
+        }
+
+        self.routes
+            .iter()
+            .filter(|((route_method, route_path), _)| {
+                route_method == method && route_path.ends_with("/*")
+            })
+            .find(|((_, route_path), _)| {
+                let prefix = &route_path[..route_path.len() - 1];
+                path.starts_with(prefix)
+            })
+            .map(|(_, handler)| handler)
+    }
+}
+
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn Error>> {
     let port = 7878u16;
@@ -12,15 +66,34 @@ async fn main() -> Result<(), Box<dyn Error>> {
     let listener = TcpListener::bind(listen_address).await.unwrap();
     println!("Listening on port {}", port);
 
+    let router = Arc::new(build_router());
+
     loop {
         let (stream, _) = listener.accept().await.unwrap();
+        let router = Arc::clone(&router);
+
         tokio::spawn(async move {
-            handle_connection(stream).await;
+            handle_connection(stream, router).await;
         });
     }
 }
 
-async fn handle_connection(mut stream: TcpStream) {
+fn build_router() -> Router {
+    let mut router = Router::new();
+
+    router.route("GET", "/", |stream| async move {
+        send_response(stream, 200, "OK", "hello.html").await;
+    });
+
+    router.route("GET", "/sleep", |stream| async move {
+        tokio::time::sleep(Duration::from_secs(5)).await;
+        send_response(stream, 200, "OK", "hello.html").await;
+    });
+
+    router
+}
+
+async fn handle_connection(mut stream: TcpStream, router: Arc<Router>) {
     let buf_reader = BufReader::new(&mut stream);
 
     let mut lines = buf_reader.lines();
@@ -28,14 +101,11 @@ async fn handle_connection(mut stream: TcpStream) {
 
     println!("Incoming request for {}", request_line);
 
-    match &request_line[..] {
-        "GET / HTTP/1.1" => send_response(stream, 200, "OK", "hello.html").await,
-        "GET /sleep HTTP/1.1" => {
-            tokio::time::sleep(Duration::from_secs(5)).await;
-            send_response(stream, 200, "OK", "hello.html").await;
-        }
-        _ => send_response(stream, 404, "NOT FOUND", "404.html").await,
-    }
+    let mut parts = request_line.split_whitespace();
+    let method = parts.next().unwrap_or("");
+    let path = parts.next().unwrap_or("/");
+
+    router.handle(method, path, stream).await;
 }
 
 async fn send_response(mut stream: TcpStream, code: u16, reason: &str, filename: &str) {